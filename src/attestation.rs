@@ -0,0 +1,93 @@
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+use crate::errors::Error;
+use crate::storage::DataKey;
+use crate::types::Attestation;
+
+/// Freshness window used when the contract hasn't configured one yet.
+const DEFAULT_FRESHNESS_WINDOW_SECONDS: u64 = 300;
+
+/// Sets the contract administrator, authorized to change contract-wide
+/// config such as the attestation freshness window. Can only be called once;
+/// subsequent calls panic so an attacker can't reassign the admin after the
+/// fact.
+pub fn initialize(env: &Env, admin: Address) {
+    assert!(
+        !env.storage().instance().has(&DataKey::Admin),
+        "already initialized"
+    );
+    env.storage().instance().set(&DataKey::Admin, &admin);
+}
+
+/// Registers the ed25519 public key `issuer` will sign attestations with.
+///
+/// Must be authorized by `issuer` so no one can register a key on another
+/// issuer's behalf.
+pub fn register_issuer_key(env: &Env, issuer: Address, public_key: BytesN<32>) {
+    issuer.require_auth();
+    env.storage()
+        .persistent()
+        .set(&DataKey::IssuerKey(issuer), &public_key);
+}
+
+/// Sets the freshness window (in seconds) attestation timestamps are checked
+/// against. Must be authorized by the contract admin set via `initialize`,
+/// since widening this window defeats `submit_attestation`'s replay/staleness
+/// guard for every issuer at once.
+pub fn set_freshness_window(env: &Env, window_seconds: u64) {
+    let admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .expect("contract not initialized");
+    admin.require_auth();
+
+    env.storage()
+        .instance()
+        .set(&DataKey::FreshnessWindow, &window_seconds);
+}
+
+fn freshness_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::FreshnessWindow)
+        .unwrap_or(DEFAULT_FRESHNESS_WINDOW_SECONDS)
+}
+
+/// Verifies `attestation` against its issuer's registered key and, if valid,
+/// stores it.
+///
+/// Rejects (without storing anything) an `id` that has already been seen, a
+/// `timestamp` outside the configured freshness window, or a signature that
+/// doesn't verify against the issuer's registered key. Signature
+/// verification itself is delegated to the host crypto function, which
+/// traps the whole invocation on a bad signature.
+pub fn submit_attestation(env: &Env, attestation: Attestation) -> Result<(), Error> {
+    let seen_key = DataKey::AttestationSeen(attestation.id);
+    if env.storage().persistent().has(&seen_key) {
+        return Err(Error::AttestationAlreadySeen);
+    }
+
+    let now = env.ledger().timestamp();
+    if now.abs_diff(attestation.timestamp) > freshness_window(env) {
+        return Err(Error::AttestationStale);
+    }
+
+    let public_key: BytesN<32> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::IssuerKey(attestation.issuer.clone()))
+        .ok_or(Error::IssuerKeyNotFound)?;
+
+    let signature = BytesN::<64>::try_from(attestation.signature.clone())
+        .map_err(|_| Error::InvalidSignatureLength)?;
+    let message = Bytes::from(attestation.payload_hash.clone());
+    env.crypto().ed25519_verify(&public_key, &message, &signature);
+
+    env.storage().persistent().set(&seen_key, &true);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Attestation(attestation.id), &attestation);
+
+    Ok(())
+}
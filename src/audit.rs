@@ -0,0 +1,250 @@
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, String};
+
+use crate::errors::Error;
+use crate::storage::DataKey;
+use crate::types::{AuditLog, InteractionSession, OperationContext};
+
+const GENESIS_HASH: [u8; 32] = [0u8; 32];
+
+/// Starts a new interaction session for `initiator`, seeding its hash chain
+/// with the all-zero genesis `prev_hash`. Must be authorized by `initiator`
+/// so no one can open a session (and attribute its operations) on another
+/// address's behalf.
+pub fn init_session(env: &Env, session_id: u64, initiator: Address, nonce: u64) -> InteractionSession {
+    initiator.require_auth();
+
+    let session = InteractionSession {
+        session_id,
+        initiator,
+        created_at: env.ledger().timestamp(),
+        operation_count: 0,
+        nonce,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::Session(session_id), &session);
+    env.storage().persistent().set(
+        &DataKey::SessionTail(session_id),
+        &BytesN::from_array(env, &GENESIS_HASH),
+    );
+    session
+}
+
+/// Appends a hash-chained entry to `session_id`'s audit log, rejecting an
+/// `operation_index` that doesn't match the session's next expected index.
+///
+/// The caller names `actor` explicitly as who is logging this entry, so
+/// `actor` must authorize the call — unlike `record_operation`, which
+/// piggy-backs a log entry onto some other operation's already-authorized
+/// actor (or a non-authorizing attribute) and must not re-demand auth from it.
+///
+/// Returns `Error::SessionNotFound` rather than trapping if `session_id`
+/// hasn't been initialized via `init_session`, and
+/// `Error::OutOfOrderOperationIndex` rather than trapping if `operation_index`
+/// doesn't match the session's next expected index.
+pub fn append_entry(
+    env: &Env,
+    log_id: u64,
+    session_id: u64,
+    operation_index: u64,
+    operation_type: String,
+    status: String,
+    result_data: u64,
+    actor: Address,
+) -> Result<AuditLog, Error> {
+    actor.require_auth();
+
+    let session: InteractionSession = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Session(session_id))
+        .ok_or(Error::SessionNotFound)?;
+    if operation_index != session.operation_count {
+        return Err(Error::OutOfOrderOperationIndex);
+    }
+
+    append(
+        env,
+        log_id,
+        session_id,
+        operation_index,
+        operation_type,
+        status,
+        result_data,
+        actor,
+    )
+}
+
+/// Appends the next hash-chained entry to `session_id`'s audit log,
+/// auto-assigning its `operation_index` and `log_id`. Used by entry points
+/// that append to the log as a side effect of some other operation rather
+/// than driving the log directly, so `actor` here is an attribution on the
+/// entry, not a party being asked to authorize anything — it's the entry
+/// point's job to have already authorized whatever needed it.
+///
+/// Returns `Error::SessionNotFound` rather than trapping if `session_id`
+/// hasn't been initialized via `init_session`.
+pub fn record_operation(
+    env: &Env,
+    session_id: u64,
+    operation_type: String,
+    status: String,
+    result_data: u64,
+    actor: Address,
+) -> Result<AuditLog, Error> {
+    let session: InteractionSession = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Session(session_id))
+        .ok_or(Error::SessionNotFound)?;
+
+    append(
+        env,
+        next_log_id(env),
+        session_id,
+        session.operation_count,
+        operation_type,
+        status,
+        result_data,
+        actor,
+    )
+}
+
+/// Returns the address that opened `session_id`, for entry points that want
+/// to attribute a log entry to the caller driving the session rather than to
+/// some unrelated piece of business data.
+pub fn session_initiator(env: &Env, session_id: u64) -> Result<Address, Error> {
+    let session: InteractionSession = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Session(session_id))
+        .ok_or(Error::SessionNotFound)?;
+    Ok(session.initiator)
+}
+
+fn next_log_id(env: &Env) -> u64 {
+    let log_id: u64 = env.storage().instance().get(&DataKey::NextLogId).unwrap_or(0);
+    env.storage().instance().set(&DataKey::NextLogId, &(log_id + 1));
+    log_id
+}
+
+fn append(
+    env: &Env,
+    log_id: u64,
+    session_id: u64,
+    operation_index: u64,
+    operation_type: String,
+    status: String,
+    result_data: u64,
+    actor: Address,
+) -> Result<AuditLog, Error> {
+    // Auth (when required) is enforced by the caller — `append_entry`
+    // requires it of the explicitly-named `actor`; `record_operation` does
+    // not, since its `actor` is an attribution piggy-backed onto an
+    // operation that authorized whatever it needed on its own terms.
+    let mut session: InteractionSession = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Session(session_id))
+        .ok_or(Error::SessionNotFound)?;
+
+    let prev_hash: BytesN<32> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::SessionTail(session_id))
+        .expect("session tail missing");
+
+    let operation = OperationContext {
+        session_id,
+        operation_index,
+        operation_type,
+        timestamp: env.ledger().timestamp(),
+        status,
+        result_data,
+    };
+    let entry_hash = hash_entry(env, log_id, &operation, &actor, &prev_hash);
+
+    let entry = AuditLog {
+        log_id,
+        session_id,
+        operation,
+        actor,
+        prev_hash,
+        entry_hash: entry_hash.clone(),
+    };
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::AuditEntry(session_id, operation_index), &entry);
+    env.storage()
+        .persistent()
+        .set(&DataKey::SessionTail(session_id), &entry_hash);
+
+    session.operation_count += 1;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Session(session_id), &session);
+
+    Ok(entry)
+}
+
+/// Walks `session_id`'s entries in `operation_index` order, recomputing each
+/// `entry_hash` from the one before it, and confirms the recomputed tail
+/// matches what's stored. Any reordering, insertion, or mutation of an entry
+/// breaks the chain and this returns `false`.
+pub fn verify_session(env: &Env, session_id: u64) -> bool {
+    let session: InteractionSession = match env.storage().persistent().get(&DataKey::Session(session_id)) {
+        Some(session) => session,
+        None => return false,
+    };
+    let stored_tail: BytesN<32> = match env
+        .storage()
+        .persistent()
+        .get(&DataKey::SessionTail(session_id))
+    {
+        Some(tail) => tail,
+        None => return false,
+    };
+
+    let mut prev_hash = BytesN::from_array(env, &GENESIS_HASH);
+    for operation_index in 0..session.operation_count {
+        let entry: AuditLog = match env
+            .storage()
+            .persistent()
+            .get(&DataKey::AuditEntry(session_id, operation_index))
+        {
+            Some(entry) => entry,
+            None => return false,
+        };
+        if entry.prev_hash != prev_hash {
+            return false;
+        }
+        let recomputed = hash_entry(env, entry.log_id, &entry.operation, &entry.actor, &prev_hash);
+        if recomputed != entry.entry_hash {
+            return false;
+        }
+        prev_hash = recomputed;
+    }
+
+    prev_hash == stored_tail
+}
+
+fn hash_entry(
+    env: &Env,
+    log_id: u64,
+    operation: &OperationContext,
+    actor: &Address,
+    prev_hash: &BytesN<32>,
+) -> BytesN<32> {
+    let mut message = Bytes::new(env);
+    message.append(&log_id.to_xdr(env));
+    message.append(&operation.session_id.to_xdr(env));
+    message.append(&operation.operation_index.to_xdr(env));
+    message.append(&operation.operation_type.to_xdr(env));
+    message.append(&operation.timestamp.to_xdr(env));
+    message.append(&operation.status.to_xdr(env));
+    message.append(&operation.result_data.to_xdr(env));
+    message.append(&actor.to_xdr(env));
+    message.append(&prev_hash.to_xdr(env));
+    env.crypto().sha256(&message).to_bytes()
+}
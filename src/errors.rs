@@ -0,0 +1,15 @@
+use soroban_sdk::contracterror;
+
+/// Contract-level error codes returned to callers instead of raw host traps.
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    IssuerKeyNotFound = 1,
+    InvalidSignatureLength = 2,
+    AttestationAlreadySeen = 3,
+    AttestationStale = 4,
+    NoValidQuotes = 5,
+    SessionNotFound = 6,
+    OutOfOrderOperationIndex = 7,
+}
@@ -0,0 +1,22 @@
+use soroban_sdk::{Address, Env};
+
+use crate::types::OperationContext;
+
+/// Publishes a structured event mirroring `ctx`, so off-chain indexers can
+/// reconstruct session history from the event stream instead of polling
+/// `AuditLog` storage. Topics encode the operation type and the acting
+/// address; the data payload carries `ctx`'s session id, operation index,
+/// status, and result data (its timestamp is not included).
+///
+/// Entry points call this right after appending the corresponding
+/// `AuditLog` entry, so the event stream and the on-chain log never diverge.
+pub fn emit_op(env: &Env, ctx: &OperationContext, actor: &Address) {
+    let topics = (ctx.operation_type.clone(), actor.clone());
+    let data = (
+        ctx.session_id,
+        ctx.operation_index,
+        ctx.status.clone(),
+        ctx.result_data,
+    );
+    env.events().publish(topics, data);
+}
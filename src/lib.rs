@@ -0,0 +1,236 @@
+#![no_std]
+
+mod attestation;
+mod audit;
+mod errors;
+mod events;
+mod quotes;
+mod registry;
+mod storage;
+#[cfg(test)]
+mod test;
+mod types;
+
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
+
+pub use errors::Error;
+pub use types::*;
+
+#[contract]
+pub struct AnchorKitContract;
+
+#[contractimpl]
+impl AnchorKitContract {
+    /// Sets the contract administrator. Can only be called once.
+    pub fn initialize(env: Env, admin: Address) {
+        attestation::initialize(&env, admin);
+    }
+
+    /// Registers the ed25519 public key `issuer` will sign attestations with.
+    pub fn register_issuer_key(env: Env, issuer: Address, public_key: BytesN<32>) {
+        attestation::register_issuer_key(&env, issuer, public_key);
+    }
+
+    /// Sets the freshness window (in seconds) attestation timestamps are
+    /// checked against. Requires auth from the contract admin.
+    pub fn set_freshness_window(env: Env, window_seconds: u64) {
+        attestation::set_freshness_window(&env, window_seconds);
+    }
+
+    /// Verifies and stores an attestation, rejecting replays, stale
+    /// timestamps, and signatures that don't match the issuer's registered
+    /// key, then records the outcome in `session_id`'s audit log.
+    ///
+    /// Returns `Error::SessionNotFound` rather than trapping if `session_id`
+    /// hasn't been initialized via `init_session`.
+    pub fn submit_attestation(
+        env: Env,
+        session_id: u64,
+        attestation: Attestation,
+    ) -> Result<(), Error> {
+        attestation::submit_attestation(&env, attestation.clone())?;
+        // The issuer authorized the attestation itself via its ed25519
+        // signature, not by co-signing this transaction, so the log entry is
+        // attributed to whoever is driving the session rather than to the
+        // issuer.
+        let actor = audit::session_initiator(&env, session_id)?;
+        record_and_emit(
+            &env,
+            session_id,
+            String::from_str(&env, "attestation_submit"),
+            String::from_str(&env, "success"),
+            attestation.id,
+            actor,
+        )
+    }
+
+    /// Registers `anchor`'s endpoint for `service`, authorized by `attestor`,
+    /// then records the registration in `session_id`'s audit log.
+    ///
+    /// Returns `Error::SessionNotFound` rather than trapping if `session_id`
+    /// hasn't been initialized via `init_session`.
+    pub fn register_endpoint(
+        env: Env,
+        session_id: u64,
+        anchor: Address,
+        service: ServiceType,
+        url: String,
+        attestor: Address,
+    ) -> Result<(), Error> {
+        registry::register_endpoint(&env, anchor, service, url, attestor.clone());
+        record_and_emit(
+            &env,
+            session_id,
+            String::from_str(&env, "endpoint_register"),
+            String::from_str(&env, "success"),
+            0,
+            attestor,
+        )
+    }
+
+    /// Activates or deactivates `anchor`'s endpoint for `service`, authorized
+    /// by the endpoint's recorded attestor, then records the change in
+    /// `session_id`'s audit log.
+    ///
+    /// Returns `Error::SessionNotFound` rather than trapping if `session_id`
+    /// hasn't been initialized via `init_session`.
+    pub fn set_endpoint_active(
+        env: Env,
+        session_id: u64,
+        anchor: Address,
+        service: ServiceType,
+        active: bool,
+    ) -> Result<(), Error> {
+        registry::set_endpoint_active(&env, anchor.clone(), service.clone(), active);
+        let endpoint = registry::get_endpoint(&env, anchor, service)
+            .expect("endpoint must exist after set_endpoint_active");
+        let operation_type = if active {
+            "endpoint_activate"
+        } else {
+            "endpoint_deactivate"
+        };
+        record_and_emit(
+            &env,
+            session_id,
+            String::from_str(&env, operation_type),
+            String::from_str(&env, "success"),
+            0,
+            endpoint.attestor,
+        )
+    }
+
+    /// Looks up `anchor`'s endpoint for `service`, if one has been registered.
+    pub fn get_endpoint(env: Env, anchor: Address, service: ServiceType) -> Option<Endpoint> {
+        registry::get_endpoint(&env, anchor, service)
+    }
+
+    /// Returns every currently active endpoint registered for `service`,
+    /// across all anchors.
+    pub fn resolve_endpoints(env: Env, service: ServiceType) -> Vec<Endpoint> {
+        registry::resolve_endpoints(&env, service)
+    }
+
+    /// Ranks `quotes` for `request` by net effective outcome, records the
+    /// comparison in `session_id`'s audit log, and returns the winner
+    /// alongside every quote that was still valid.
+    ///
+    /// Returns `Error::SessionNotFound` rather than trapping if `session_id`
+    /// hasn't been initialized via `init_session`.
+    pub fn compare_quotes(
+        env: Env,
+        session_id: u64,
+        request: QuoteRequest,
+        quotes: Vec<QuoteData>,
+    ) -> Result<RateComparison, Error> {
+        let comparison = quotes::compare_quotes(&env, request, quotes)?;
+        // The winning anchor didn't authorize anything here — it's business
+        // data about which quote won — so the log entry is attributed to the
+        // requester driving the session instead.
+        let actor = audit::session_initiator(&env, session_id)?;
+        record_and_emit(
+            &env,
+            session_id,
+            String::from_str(&env, "quote_comparison"),
+            String::from_str(&env, "success"),
+            comparison.best_quote.quote_id,
+            actor,
+        )?;
+        Ok(comparison)
+    }
+
+    /// Starts a new interaction session for `initiator`, seeding its audit
+    /// log's hash chain and recording the session's own creation as its
+    /// first entry.
+    pub fn init_session(
+        env: Env,
+        session_id: u64,
+        initiator: Address,
+        nonce: u64,
+    ) -> InteractionSession {
+        let session = audit::init_session(&env, session_id, initiator.clone(), nonce);
+        record_and_emit(
+            &env,
+            session_id,
+            String::from_str(&env, "session_init"),
+            String::from_str(&env, "success"),
+            session_id,
+            initiator,
+        )
+        .expect("session was just created by init_session above");
+        session
+    }
+
+    /// Appends a hash-chained audit entry to `session_id`, authorized by
+    /// `actor`, the party named as logging it.
+    ///
+    /// Returns `Error::SessionNotFound` rather than trapping if `session_id`
+    /// hasn't been initialized via `init_session`, and
+    /// `Error::OutOfOrderOperationIndex` rather than trapping if
+    /// `operation_index` doesn't match the session's next expected index.
+    pub fn log_operation(
+        env: Env,
+        log_id: u64,
+        session_id: u64,
+        operation_index: u64,
+        operation_type: String,
+        status: String,
+        result_data: u64,
+        actor: Address,
+    ) -> Result<AuditLog, Error> {
+        let entry = audit::append_entry(
+            &env,
+            log_id,
+            session_id,
+            operation_index,
+            operation_type,
+            status,
+            result_data,
+            actor.clone(),
+        )?;
+        events::emit_op(&env, &entry.operation, &actor);
+        Ok(entry)
+    }
+
+    /// Walks `session_id`'s audit log and confirms its hash chain is intact.
+    pub fn verify_session(env: Env, session_id: u64) -> bool {
+        audit::verify_session(&env, session_id)
+    }
+}
+
+/// Records an operation in `session_id`'s audit log and publishes the
+/// matching event, so the two never diverge.
+///
+/// Returns `Error::SessionNotFound` rather than trapping if `session_id`
+/// hasn't been initialized via `init_session`.
+fn record_and_emit(
+    env: &Env,
+    session_id: u64,
+    operation_type: String,
+    status: String,
+    result_data: u64,
+    actor: Address,
+) -> Result<(), Error> {
+    let entry = audit::record_operation(env, session_id, operation_type, status, result_data, actor.clone())?;
+    events::emit_op(env, &entry.operation, &actor);
+    Ok(())
+}
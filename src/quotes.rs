@@ -0,0 +1,81 @@
+use soroban_sdk::{Env, Vec};
+
+use crate::errors::Error;
+use crate::types::{QuoteData, QuoteRequest, RateComparison, ServiceType};
+
+/// Compares `quotes` for `request`, ranking them by net effective outcome
+/// for `request.amount` and returning the winner alongside every quote that
+/// was still valid. Expired, out-of-range/mismatched, and malformed quotes
+/// (zero `rate`, `fee_percentage` over 100%) are excluded rather than
+/// trapping the whole call.
+pub fn compare_quotes(
+    env: &Env,
+    request: QuoteRequest,
+    quotes: Vec<QuoteData>,
+) -> Result<RateComparison, Error> {
+    let now = env.ledger().timestamp();
+
+    let mut valid = Vec::new(env);
+    for quote in quotes.iter() {
+        if is_valid(&request, &quote, now) {
+            valid.push_back(quote);
+        }
+    }
+
+    let mut best: Option<QuoteData> = None;
+    for quote in valid.iter() {
+        let is_better = match &best {
+            None => true,
+            Some(current) => ranks_higher(&request, &quote, current),
+        };
+        if is_better {
+            best = Some(quote);
+        }
+    }
+    let best_quote = best.ok_or(Error::NoValidQuotes)?;
+
+    Ok(RateComparison {
+        best_quote,
+        all_quotes: valid,
+        comparison_timestamp: now,
+    })
+}
+
+fn is_valid(request: &QuoteRequest, quote: &QuoteData, now: u64) -> bool {
+    quote.valid_until >= now
+        && request.amount >= quote.minimum_amount
+        && request.amount <= quote.maximum_amount
+        && quote.base_asset == request.base_asset
+        && quote.quote_asset == request.quote_asset
+        && quote.rate != 0
+        && quote.fee_percentage <= 10_000
+}
+
+/// Net amount the requester would end up with for `request.amount` under
+/// `quote`, after applying the rate and fee, in the quote asset.
+fn net_effective(request: &QuoteRequest, quote: &QuoteData) -> u128 {
+    let amount = request.amount as u128;
+    let rate = quote.rate as u128;
+    let fee_percentage = quote.fee_percentage as u128;
+
+    let gross = match request.operation_type {
+        ServiceType::Deposits => amount * rate / 10_000,
+        ServiceType::Withdrawals => amount * 10_000 / rate,
+        _ => amount,
+    };
+    gross * (10_000 - fee_percentage) / 10_000
+}
+
+/// True if `candidate` should replace `current` as the best quote: higher
+/// net effective outcome, tie-broken by lower fee then lower `quote_id`.
+fn ranks_higher(request: &QuoteRequest, candidate: &QuoteData, current: &QuoteData) -> bool {
+    let candidate_net = net_effective(request, candidate);
+    let current_net = net_effective(request, current);
+    if candidate_net != current_net {
+        return candidate_net > current_net;
+    }
+    if candidate.fee_percentage != current.fee_percentage {
+        return candidate.fee_percentage < current.fee_percentage;
+    }
+    candidate.quote_id < current.quote_id
+}
@@ -0,0 +1,91 @@
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::storage::DataKey;
+use crate::types::{Endpoint, ServiceType};
+
+/// Registers `anchor`'s endpoint for `service`, authorized by the attestor
+/// that will own the endpoint's lifecycle.
+///
+/// If `(anchor, service)` already has an endpoint, replacing it also
+/// requires auth from the *existing* record's attestor, so a caller can't
+/// hijack another attestor's endpoint by simply authorizing as themselves.
+pub fn register_endpoint(
+    env: &Env,
+    anchor: Address,
+    service: ServiceType,
+    url: String,
+    attestor: Address,
+) {
+    let key = DataKey::Endpoint(anchor.clone(), service.clone());
+    if let Some(existing) = env.storage().persistent().get::<_, Endpoint>(&key) {
+        existing.attestor.require_auth();
+    }
+    attestor.require_auth();
+
+    let endpoint = Endpoint {
+        url,
+        attestor,
+        is_active: true,
+    };
+    store_endpoint(env, &anchor, &service, &endpoint);
+}
+
+/// Activates or deactivates `anchor`'s endpoint for `service`, authorized by
+/// the attestor recorded on the endpoint. The record is kept either way so
+/// the lifecycle stays auditable.
+pub fn set_endpoint_active(env: &Env, anchor: Address, service: ServiceType, active: bool) {
+    let key = DataKey::Endpoint(anchor.clone(), service.clone());
+    let mut endpoint: Endpoint = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .expect("endpoint not registered");
+    endpoint.attestor.require_auth();
+
+    endpoint.is_active = active;
+    store_endpoint(env, &anchor, &service, &endpoint);
+}
+
+/// Looks up `anchor`'s endpoint for `service`, if one has been registered.
+pub fn get_endpoint(env: &Env, anchor: Address, service: ServiceType) -> Option<Endpoint> {
+    env.storage().persistent().get(&DataKey::Endpoint(anchor, service))
+}
+
+/// Returns every currently active endpoint registered for `service`, across
+/// all anchors, so a client SDK can discover who serves it without off-chain
+/// configuration.
+pub fn resolve_endpoints(env: &Env, service: ServiceType) -> Vec<Endpoint> {
+    let anchors: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ServiceAnchors(service.clone()))
+        .unwrap_or(Vec::new(env));
+
+    let mut active = Vec::new(env);
+    for anchor in anchors.iter() {
+        let key = DataKey::Endpoint(anchor, service.clone());
+        if let Some(endpoint) = env.storage().persistent().get::<_, Endpoint>(&key) {
+            if endpoint.is_active {
+                active.push_back(endpoint);
+            }
+        }
+    }
+    active
+}
+
+fn store_endpoint(env: &Env, anchor: &Address, service: &ServiceType, endpoint: &Endpoint) {
+    let key = DataKey::Endpoint(anchor.clone(), service.clone());
+    let is_new = !env.storage().persistent().has(&key);
+    env.storage().persistent().set(&key, endpoint);
+
+    if is_new {
+        let anchors_key = DataKey::ServiceAnchors(service.clone());
+        let mut anchors: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&anchors_key)
+            .unwrap_or(Vec::new(env));
+        anchors.push_back(anchor.clone());
+        env.storage().persistent().set(&anchors_key, &anchors);
+    }
+}
@@ -0,0 +1,35 @@
+use soroban_sdk::{contracttype, Address};
+
+use crate::types::ServiceType;
+
+/// Storage keys for all contract-managed state.
+///
+/// Grouped in one enum per Soroban convention so every subsystem shares the
+/// same persistent/instance key space without colliding.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// Registered ed25519 public key for a given attestation issuer.
+    IssuerKey(Address),
+    /// Marks an attestation `id` as already consumed (replay protection).
+    AttestationSeen(u64),
+    /// Stored attestation, keyed by its `id`.
+    Attestation(u64),
+    /// Configurable freshness window (seconds) for attestation timestamps.
+    FreshnessWindow,
+    /// An anchor's registered endpoint for a given service type.
+    Endpoint(Address, ServiceType),
+    /// Anchors that have ever registered an endpoint for a given service type,
+    /// used to enumerate candidates for `resolve_endpoints`.
+    ServiceAnchors(ServiceType),
+    /// An interaction session's metadata, keyed by `session_id`.
+    Session(u64),
+    /// The hash chain's current tail for a session, keyed by `session_id`.
+    SessionTail(u64),
+    /// An audit log entry, keyed by `(session_id, operation_index)`.
+    AuditEntry(u64, u64),
+    /// Monotonically increasing counter used to assign new `AuditLog::log_id`s.
+    NextLogId,
+    /// The contract administrator, authorized to change contract-wide config.
+    Admin,
+}
@@ -0,0 +1,309 @@
+#![cfg(test)]
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::testutils::{Address as _, Ledger as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::{Address, Bytes, BytesN, Env, IntoVal, String, Vec};
+
+use crate::{
+    AnchorKitContract, AnchorKitContractClient, Attestation, Error, QuoteData, QuoteRequest,
+    ServiceType,
+};
+
+fn issuer_keypair() -> SigningKey {
+    SigningKey::from_bytes(&[7u8; 32])
+}
+
+fn sign_attestation(
+    env: &Env,
+    signing_key: &SigningKey,
+    issuer: &Address,
+    subject: &Address,
+    id: u64,
+    timestamp: u64,
+) -> Attestation {
+    let payload_hash = BytesN::from_array(env, &[9u8; 32]);
+    let signature = signing_key.sign(&payload_hash.to_array());
+    Attestation {
+        id,
+        issuer: issuer.clone(),
+        subject: subject.clone(),
+        timestamp,
+        payload_hash,
+        signature: Bytes::from_slice(env, &signature.to_bytes()),
+    }
+}
+
+fn setup(env: &Env) -> (AnchorKitContractClient, Address, Address) {
+    let contract_id = env.register(AnchorKitContract, ());
+    let client = AnchorKitContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    let issuer = Address::generate(env);
+    let signing_key = issuer_keypair();
+    let public_key = BytesN::from_array(env, &signing_key.verifying_key().to_bytes());
+    client.register_issuer_key(&issuer, &public_key);
+    (client, admin, issuer)
+}
+
+#[test]
+fn submit_attestation_accepts_valid_signature() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, issuer) = setup(&env);
+    let subject = Address::generate(&env);
+    let signing_key = issuer_keypair();
+
+    let now = env.ledger().timestamp();
+    let attestation = sign_attestation(&env, &signing_key, &issuer, &subject, 1, now);
+
+    client.init_session(&1, &subject, &0);
+    client.submit_attestation(&1, &attestation);
+}
+
+#[test]
+fn submit_attestation_rejects_replay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, issuer) = setup(&env);
+    let subject = Address::generate(&env);
+    let signing_key = issuer_keypair();
+
+    let now = env.ledger().timestamp();
+    let attestation = sign_attestation(&env, &signing_key, &issuer, &subject, 1, now);
+
+    client.init_session(&1, &subject, &0);
+    client.submit_attestation(&1, &attestation);
+
+    let result = client.try_submit_attestation(&1, &attestation);
+    assert!(matches!(result, Ok(Err(_))));
+}
+
+#[test]
+fn submit_attestation_rejects_stale_timestamp() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, issuer) = setup(&env);
+    let subject = Address::generate(&env);
+    let signing_key = issuer_keypair();
+
+    let stale_timestamp = 0;
+    env.ledger().set_timestamp(10_000);
+    let attestation = sign_attestation(&env, &signing_key, &issuer, &subject, 1, stale_timestamp);
+
+    client.init_session(&1, &subject, &0);
+    let result = client.try_submit_attestation(&1, &attestation);
+    assert!(matches!(result, Ok(Err(_))));
+}
+
+#[test]
+fn register_endpoint_overwrite_requires_existing_attestor_auth() {
+    let env = Env::default();
+    let contract_id = env.register(AnchorKitContract, ());
+    let client = AnchorKitContractClient::new(&env, &contract_id);
+
+    let anchor = Address::generate(&env);
+    let original_attestor = Address::generate(&env);
+    let attacker = Address::generate(&env);
+
+    env.mock_all_auths();
+    client.init_session(&1, &anchor, &0);
+    client.register_endpoint(
+        &1,
+        &anchor,
+        &ServiceType::Deposits,
+        &String::from_str(&env, "https://good.example"),
+        &original_attestor,
+    );
+
+    // Only `attacker` authorizes the overwrite attempt, not the endpoint's
+    // existing attestor, so the registry must reject it.
+    let evil_url = String::from_str(&env, "https://evil.example");
+    env.mock_auths(&[MockAuth {
+        address: &attacker,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "register_endpoint",
+            args: (
+                1u64,
+                anchor.clone(),
+                ServiceType::Deposits,
+                evil_url.clone(),
+                attacker.clone(),
+            )
+                .into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    let result = client.try_register_endpoint(
+        &1,
+        &anchor,
+        &ServiceType::Deposits,
+        &evil_url,
+        &attacker,
+    );
+    assert!(matches!(result, Ok(Err(_))));
+}
+
+fn quote(env: &Env, anchor: &Address, quote_id: u64, rate: u64, fee_percentage: u32) -> QuoteData {
+    QuoteData {
+        anchor: anchor.clone(),
+        base_asset: String::from_str(env, "USD"),
+        quote_asset: String::from_str(env, "USDC"),
+        rate,
+        fee_percentage,
+        minimum_amount: 1,
+        maximum_amount: 1_000_000,
+        valid_until: env.ledger().timestamp() + 1_000,
+        quote_id,
+    }
+}
+
+#[test]
+fn compare_quotes_picks_highest_net_effective_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AnchorKitContract, ());
+    let client = AnchorKitContractClient::new(&env, &contract_id);
+
+    let requester = Address::generate(&env);
+    client.init_session(&1, &requester, &0);
+
+    let pricier_anchor = Address::generate(&env);
+    let cheaper_anchor = Address::generate(&env);
+    let quotes = Vec::from_array(
+        &env,
+        [
+            quote(&env, &pricier_anchor, 1, 10_000, 500),
+            quote(&env, &cheaper_anchor, 2, 10_000, 100),
+        ],
+    );
+    let request = QuoteRequest {
+        base_asset: String::from_str(&env, "USD"),
+        quote_asset: String::from_str(&env, "USDC"),
+        amount: 1_000,
+        operation_type: ServiceType::Deposits,
+    };
+
+    let comparison = client.compare_quotes(&1, &request, &quotes);
+    assert_eq!(comparison.best_quote.quote_id, 2);
+}
+
+#[test]
+fn compare_quotes_excludes_zero_rate_quote_instead_of_trapping() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AnchorKitContract, ());
+    let client = AnchorKitContractClient::new(&env, &contract_id);
+
+    let requester = Address::generate(&env);
+    client.init_session(&1, &requester, &0);
+
+    let anchor = Address::generate(&env);
+    let quotes = Vec::from_array(&env, [quote(&env, &anchor, 1, 0, 100)]);
+    let request = QuoteRequest {
+        base_asset: String::from_str(&env, "USD"),
+        quote_asset: String::from_str(&env, "USDC"),
+        amount: 1_000,
+        operation_type: ServiceType::Deposits,
+    };
+
+    let result = client.try_compare_quotes(&1, &request, &quotes);
+    assert!(matches!(result, Ok(Err(_))));
+}
+
+#[test]
+fn verify_session_passes_for_an_untampered_chain() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AnchorKitContract, ());
+    let client = AnchorKitContractClient::new(&env, &contract_id);
+
+    let initiator = Address::generate(&env);
+    client.init_session(&1, &initiator, &0);
+    client.log_operation(
+        &1,
+        &1,
+        &1,
+        &String::from_str(&env, "noop"),
+        &String::from_str(&env, "success"),
+        &0,
+        &initiator,
+    );
+
+    assert!(client.verify_session(&1));
+}
+
+#[test]
+fn verify_session_detects_a_mutated_entry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AnchorKitContract, ());
+    let client = AnchorKitContractClient::new(&env, &contract_id);
+
+    let initiator = Address::generate(&env);
+    client.init_session(&1, &initiator, &0);
+    client.log_operation(
+        &1,
+        &1,
+        &1,
+        &String::from_str(&env, "noop"),
+        &String::from_str(&env, "success"),
+        &0,
+        &initiator,
+    );
+    assert!(client.verify_session(&1));
+
+    // Mutate the stored entry's result_data directly, bypassing the chain's
+    // own append path, the way a compromised storage write would.
+    env.as_contract(&contract_id, || {
+        let key = crate::storage::DataKey::AuditEntry(1, 1);
+        let mut entry: crate::types::AuditLog = env.storage().persistent().get(&key).unwrap();
+        entry.operation.result_data = 999;
+        env.storage().persistent().set(&key, &entry);
+    });
+
+    assert!(!client.verify_session(&1));
+}
+
+#[test]
+fn register_endpoint_without_init_session_returns_typed_error_not_a_trap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AnchorKitContract, ());
+    let client = AnchorKitContractClient::new(&env, &contract_id);
+
+    let anchor = Address::generate(&env);
+    let attestor = Address::generate(&env);
+
+    let result = client.try_register_endpoint(
+        &1,
+        &anchor,
+        &ServiceType::Deposits,
+        &String::from_str(&env, "https://example.com"),
+        &attestor,
+    );
+
+    assert_eq!(result, Ok(Err(Error::SessionNotFound)));
+}
+
+#[test]
+fn register_endpoint_emits_an_event_alongside_the_audit_log() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(AnchorKitContract, ());
+    let client = AnchorKitContractClient::new(&env, &contract_id);
+
+    let anchor = Address::generate(&env);
+    let attestor = Address::generate(&env);
+    client.init_session(&1, &anchor, &0);
+
+    client.register_endpoint(
+        &1,
+        &anchor,
+        &ServiceType::Deposits,
+        &String::from_str(&env, "https://example.com"),
+        &attestor,
+    );
+
+    assert!(!env.events().all().is_empty());
+}
@@ -121,5 +121,10 @@ pub struct AuditLog {
     pub operation: OperationContext,
     /// Actor performing the operation
     pub actor: Address,
-
+    /// Hash of the previous entry in this session's chain (all-zero for the
+    /// session's first entry)
+    pub prev_hash: BytesN<32>,
+    /// sha256 over this entry's fields and `prev_hash`, binding it to every
+    /// entry before it
+    pub entry_hash: BytesN<32>,
 }